@@ -0,0 +1,143 @@
+use crate::endian::*;
+
+/// Presents two buffers, `A` followed by `B`, as a single logical view: reads and
+/// writes consume `a` until it's exhausted, then continue into `b`. This mirrors
+/// the `bytes` crate's `Chain`, and is useful for scatter/gather parsing of ring
+/// buffers and segmented network frames.
+///
+/// Create one with [`chain`], [`Chain::new`] or [`DataView::chain`].
+///
+/// [`DataView::chain`]: crate::DataView::chain
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Chain<A, B> {
+    pub a: A,
+    pub b: B,
+    pub offset: usize,
+}
+
+/// Creates a [`Chain`] presenting `a` followed by `b` as a single logical view.
+///
+/// # Examples
+///
+/// ```
+/// use data_view::chain;
+///
+/// let mut view = chain([1, 2], [3, 4]);
+/// assert_eq!(view.read::<u8>().unwrap(), 1);
+/// ```
+#[inline]
+pub const fn chain<A, B>(a: A, b: B) -> Chain<A, B> {
+    Chain::new(a, b)
+}
+
+impl<A, B> Chain<A, B> {
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::Chain;
+    ///
+    /// let view = Chain::new([1, 2], [3, 4]);
+    /// ```
+    pub const fn new(a: A, b: B) -> Self {
+        Self { a, b, offset: 0 }
+    }
+}
+
+impl<A: AsRef<[u8]>, B: AsRef<[u8]>> Chain<A, B> {
+    /// Returns the combined length of both buffers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.a.as_ref().len() + self.b.as_ref().len()
+    }
+
+    /// Returns `true` if both buffers are empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads a value of type `E: Endian` from the `Chain`, transparently reading
+    /// across the `a`/`b` boundary if `E::SIZE` straddles it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::chain;
+    ///
+    /// // the `u16` straddles the boundary between the two buffers.
+    /// let mut view = chain([1], [0, 2, 3]);
+    ///
+    /// assert_eq!(view.read::<u16>().unwrap(), u16::from_ne_bytes([1, 0]));
+    /// assert_eq!(view.read::<u8>().unwrap(), 2);
+    /// ```
+    #[inline]
+    pub fn read<E: Endian>(&mut self) -> Option<E> {
+        let size = E::SIZE;
+        let total_len = self.offset.checked_add(size)?;
+        if total_len > self.len() {
+            return None;
+        }
+        let mut bytes = [0; 16];
+        self.copy_to(self.offset, &mut bytes[..size]);
+        self.offset = total_len;
+        Some(unsafe { num_from(bytes.as_ptr()) })
+    }
+
+    /// Copies `dst.len()` bytes starting at the logical `offset` out of `a`/`b`,
+    /// splitting the copy across the boundary if it straddles both buffers.
+    ///
+    /// The caller is responsible for the bounds check; this only copies.
+    fn copy_to(&self, offset: usize, dst: &mut [u8]) {
+        let a = self.a.as_ref();
+        let b = self.b.as_ref();
+        let total_len = offset + dst.len();
+        if offset >= a.len() {
+            let start = offset - a.len();
+            dst.copy_from_slice(&b[start..start + dst.len()]);
+        } else if total_len <= a.len() {
+            dst.copy_from_slice(&a[offset..total_len]);
+        } else {
+            let from_a = a.len() - offset;
+            let rest = dst.len() - from_a;
+            dst[..from_a].copy_from_slice(&a[offset..]);
+            dst[from_a..].copy_from_slice(&b[..rest]);
+        }
+    }
+}
+
+impl<A: AsMut<[u8]> + AsRef<[u8]>, B: AsMut<[u8]> + AsRef<[u8]>> Chain<A, B> {
+    /// Writes a value of type `E: Endian` to the `Chain`, transparently writing
+    /// across the `a`/`b` boundary if `E::SIZE` straddles it.
+    ///
+    /// # Panics
+    /// Panics if the offset is out of bounds.
+    #[inline]
+    pub fn write<E: Endian>(&mut self, num: E) {
+        let size = E::SIZE;
+        let total_len = self.offset.checked_add(size).expect("offset overflow");
+        assert!(total_len <= self.len());
+        let mut bytes = [0; 16];
+        unsafe { num_write_at(num, bytes.as_mut_ptr()) };
+        self.copy_from(self.offset, &bytes[..size]);
+        self.offset = total_len;
+    }
+
+    /// Copies `src` into `a`/`b` starting at the logical `offset`, splitting the
+    /// copy across the boundary if it straddles both buffers.
+    ///
+    /// The caller is responsible for the bounds check; this only copies.
+    fn copy_from(&mut self, offset: usize, src: &[u8]) {
+        let a_len = self.a.as_ref().len();
+        let total_len = offset + src.len();
+        if offset >= a_len {
+            let start = offset - a_len;
+            self.b.as_mut()[start..start + src.len()].copy_from_slice(src);
+        } else if total_len <= a_len {
+            self.a.as_mut()[offset..total_len].copy_from_slice(src);
+        } else {
+            let from_a = a_len - offset;
+            self.a.as_mut()[offset..].copy_from_slice(&src[..from_a]);
+            self.b.as_mut()[..src.len() - from_a].copy_from_slice(&src[from_a..]);
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use core::convert::TryInto;
+use core::mem::{align_of, size_of};
+use core::ptr;
+use core::slice;
 
+use crate::chain::Chain;
 use crate::endian::*;
+use crate::marker::{AsBytes, FromBytes};
+use crate::take::Take;
 
 /// This struct represents a data view for reading and writing data in a byte array.
 /// When read/write, This increment current offset by the size of the value.
@@ -8,6 +14,19 @@ use crate::endian::*;
 pub struct DataView<T> {
     pub data: T,
     pub offset: usize,
+    /// The byte order used by [`read_into`], [`read_array`] and [`write_from`].
+    /// [`read_with`]/[`write_with`] take their own order argument, and `read`/`write`
+    /// always use the compile-time byte order.
+    ///
+    /// [`read_into`]: Self::read_into
+    /// [`read_array`]: Self::read_array
+    /// [`write_from`]: Self::write_from
+    /// [`read_with`]: Self::read_with
+    /// [`write_with`]: Self::write_with
+    pub order: Order,
+    /// The offset recorded by the last call to [`mark`](Self::mark), used by
+    /// [`reset_to_mark`](Self::reset_to_mark) and [`distance_from_mark`](Self::distance_from_mark).
+    mark: usize,
 }
 
 impl<T> DataView<T> {
@@ -19,7 +38,158 @@ impl<T> DataView<T> {
     /// let view = DataView::new([0; 16]);
     /// ```
     pub const fn new(data: T) -> Self {
-        Self { data, offset: 0 }
+        Self {
+            data,
+            offset: 0,
+            order: Order::Native,
+            mark: 0,
+        }
+    }
+
+    /// Creates a new `DataView` whose [`order`](Self::order) is the given runtime byte
+    /// `order`, used by the bulk [`read_into`](Self::read_into),
+    /// [`read_array`](Self::read_array) and [`write_from`](Self::write_from) methods.
+    /// `read`/`write` still use the compile-time byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{DataView, Order};
+    ///
+    /// let view = DataView::with_order([0; 16], Order::Big);
+    /// ```
+    pub const fn with_order(data: T, order: Order) -> Self {
+        Self {
+            data,
+            offset: 0,
+            order,
+            mark: 0,
+        }
+    }
+
+    /// Records the current `offset`, so it can later be restored with [`reset_to_mark`].
+    ///
+    /// This enables speculative parsing: try to read a structure, and if it turns out
+    /// to be invalid, roll the offset back to where reading started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]);
+    ///
+    /// view.mark();
+    /// view.offset = 4;
+    /// view.reset_to_mark();
+    /// assert_eq!(view.offset, 0);
+    /// ```
+    ///
+    /// [`reset_to_mark`]: Self::reset_to_mark
+    #[inline]
+    pub fn mark(&mut self) {
+        self.mark = self.offset;
+    }
+
+    /// Restores `offset` to the position recorded by the last call to [`mark`].
+    ///
+    /// [`mark`]: Self::mark
+    #[inline]
+    pub fn reset_to_mark(&mut self) {
+        self.offset = self.mark;
+    }
+
+    /// Returns the number of bytes consumed since the last call to [`mark`].
+    ///
+    /// Since `offset` is a public field, it's possible for it to have moved before
+    /// the mark (e.g. via [`rewind`](Self::rewind)); in that case this saturates to `0`
+    /// instead of underflowing.
+    ///
+    /// [`mark`]: Self::mark
+    #[inline]
+    pub fn distance_from_mark(&self) -> usize {
+        self.offset.saturating_sub(self.mark)
+    }
+
+    /// Advances the offset by `n` bytes. Returns `None` (leaving the offset unchanged)
+    /// if `offset + n` would overflow.
+    ///
+    /// Note this doesn't check against the buffer's length; a subsequent read/write
+    /// still performs its own bounds check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]);
+    ///
+    /// view.advance(2).unwrap();
+    /// assert_eq!(view.offset, 2);
+    /// ```
+    #[inline]
+    pub fn advance(&mut self, n: usize) -> Option<()> {
+        self.offset = self.offset.checked_add(n)?;
+        Some(())
+    }
+
+    /// Moves the offset back by `n` bytes. Returns `None` (leaving the offset unchanged)
+    /// if `n` is greater than the current offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]);
+    ///
+    /// view.offset = 3;
+    /// view.rewind(1).unwrap();
+    /// assert_eq!(view.offset, 2);
+    /// ```
+    #[inline]
+    pub fn rewind(&mut self, n: usize) -> Option<()> {
+        self.offset = self.offset.checked_sub(n)?;
+        Some(())
+    }
+
+    /// Chains this view's buffer with `other`, presenting both as a single logical
+    /// view that reads/writes out of `self.data` until it's exhausted, then
+    /// continues into `other`. The current `offset` is carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2]).chain([3, 4]);
+    /// assert_eq!(view.read::<u8>().unwrap(), 1);
+    /// ```
+    #[inline]
+    pub fn chain<B>(self, other: B) -> Chain<T, B> {
+        Chain {
+            a: self.data,
+            b: other,
+            offset: self.offset,
+        }
+    }
+
+    /// Wraps this view in a [`Take`] adapter that caps reads at `limit` bytes,
+    /// regardless of how much data remains in the underlying buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]).take(2);
+    /// assert_eq!(view.read::<u8>(), Some(1));
+    /// assert_eq!(view.read::<u8>(), Some(2));
+    /// assert_eq!(view.read::<u8>(), None);
+    /// ```
+    #[inline]
+    pub fn take(self, limit: usize) -> Take<T> {
+        Take::new(self, limit)
     }
 }
 
@@ -63,6 +233,26 @@ impl<T: AsRef<[u8]>> DataView<T> {
             .map(|bytes| unsafe { num_from(bytes.as_ptr()) })
     }
 
+    /// Reads a value of type `E: Endian` from the DataView, using the given runtime byte `order`
+    /// instead of the view's native byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{DataView, Order};
+    ///
+    /// let mut view = DataView::from([0; 4]);
+    ///
+    /// view.write_with::<u16>(42, Order::Big);
+    /// view.offset = 0;
+    /// assert_eq!(view.read_with::<u16>(Order::Big).unwrap(), 42);
+    /// ```
+    #[inline]
+    pub fn read_with<E: Endian>(&mut self, order: Order) -> Option<E> {
+        self.read_slice(E::SIZE)
+            .map(|bytes| unsafe { E::__read_at_with__(bytes.as_ptr(), order) })
+    }
+
     /// Reads a value of type `E: Endian` from the DataView, without doing bounds checking.
     /// For a safe alternative see [`read`].
     ///
@@ -154,6 +344,126 @@ impl<T: AsRef<[u8]>> DataView<T> {
     pub unsafe fn read_buf_unchecked<const N: usize>(&mut self) -> [u8; N] {
         self.read_slice_unchecked(N).try_into().unwrap_unchecked()
     }
+
+    /// Reads `dst.len() * E::SIZE` contiguous bytes in a single bounds check, filling
+    /// `dst` with the decoded `E` values. Endian correction is only applied
+    /// element-by-element when `self.order` differs from the target's native byte
+    /// order, so this is much faster than calling [`read`](Self::read) in a loop when
+    /// decoding large arrays of samples or pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 0, 2, 0]);
+    /// let mut dst = [0_u16; 2];
+    ///
+    /// view.read_into(&mut dst).unwrap();
+    /// assert_eq!(dst, [u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]);
+    /// ```
+    pub fn read_into<E: Endian>(&mut self, dst: &mut [E]) -> Option<()> {
+        let order = self.order;
+        let bytes = self.read_slice(dst.len() * E::SIZE)?;
+        // `Order::Native` is the compile-time byte order; a plain copy is only
+        // correct when that order is also the target's native one.
+        let native = cfg!(any(
+            feature = "NE",
+            all(target_endian = "big", feature = "BE"),
+            all(target_endian = "little", not(any(feature = "BE", feature = "NE"))),
+        ));
+        if order == Order::Native && native {
+            unsafe {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), dst.as_mut_ptr() as *mut u8, bytes.len());
+            }
+        } else {
+            for (i, e) in dst.iter_mut().enumerate() {
+                *e = unsafe { E::__read_at_with__(bytes.as_ptr().add(i * E::SIZE), order) };
+            }
+        }
+        Some(())
+    }
+
+    /// Reads a fixed-size array of `N` values of type `E: Endian`, in a single bounds
+    /// check. See [`read_into`](Self::read_into) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 0, 2, 0]);
+    /// assert_eq!(
+    ///     view.read_array::<u16, 2>().unwrap(),
+    ///     [u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]
+    /// );
+    /// ```
+    pub fn read_array<E: Endian, const N: usize>(&mut self) -> Option<[E; N]> {
+        let mut dst = [E::default(); N];
+        self.read_into(&mut dst)?;
+        Some(dst)
+    }
+
+    /// Reinterprets the next `size_of::<U>()` bytes as a reference to `U`, without copying,
+    /// and advances the offset past them.
+    ///
+    /// Returns `None` (leaving the offset unchanged) if `offset + size_of::<U>()` overflows,
+    /// if there aren't enough bytes remaining, or if the address at the current offset doesn't meet
+    /// `U`'s alignment requirement (always satisfied when `U: Unaligned`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(*view.cast::<u8>().unwrap(), 1);
+    /// assert_eq!(*view.cast::<u8>().unwrap(), 2);
+    /// ```
+    #[inline]
+    pub fn cast<U: FromBytes>(&mut self) -> Option<&U> {
+        let data = self.data.as_ref();
+        let total_len = self.offset.checked_add(size_of::<U>())?;
+        let bytes = data.get(self.offset..total_len)?;
+        let ptr = bytes.as_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        self.offset = total_len;
+        Some(unsafe { &*(ptr as *const U) })
+    }
+
+    /// Reinterprets the next `size_of::<U>() * len` bytes as a slice of `U`, without copying,
+    /// and advances the offset past them.
+    ///
+    /// Returns `None` (leaving the offset unchanged) if `size_of::<U>() * len` overflows,
+    /// if there aren't enough bytes remaining, or if the address at the current offset doesn't meet
+    /// `U`'s alignment requirement (always satisfied when `U: Unaligned`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(view.cast_slice::<u8>(2).unwrap(), [1, 2]);
+    /// assert_eq!(view.cast_slice::<u8>(2).unwrap(), [3, 4]);
+    /// ```
+    #[inline]
+    pub fn cast_slice<U: FromBytes>(&mut self, len: usize) -> Option<&[U]> {
+        let len_in_bytes = len.checked_mul(size_of::<U>())?;
+        let data = self.data.as_ref();
+        let total_len = self.offset.checked_add(len_in_bytes)?;
+        let bytes = data.get(self.offset..total_len)?;
+        let ptr = bytes.as_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        self.offset = total_len;
+        Some(unsafe { slice::from_raw_parts(ptr as *const U, len) })
+    }
 }
 
 impl<T: AsMut<[u8]>> DataView<T> {
@@ -175,12 +485,80 @@ impl<T: AsMut<[u8]>> DataView<T> {
     #[inline]
     pub fn write<E: Endian>(&mut self, num: E) {
         let dst = self.data.as_mut();
-        let total_len = self.offset + E::SIZE;
+        let total_len = self.offset.checked_add(E::SIZE).expect("offset overflow");
         assert!(total_len <= dst.len());
         unsafe { num_write_at(num, dst.as_mut_ptr().add(self.offset)) };
         self.offset = total_len;
     }
 
+    /// Writes `src` to the data view in a single bounds check. Endian correction is
+    /// only applied element-by-element when `self.order` differs from the target's
+    /// native byte order, so this is much faster than calling [`write`](Self::write)
+    /// in a loop when encoding large arrays of samples or pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([0; 4]);
+    /// view.write_from(&[1_u16, 2_u16]);
+    /// ```
+    /// # Panics
+    /// Panics if the offset is out of bounds.
+    pub fn write_from<E: Endian>(&mut self, src: &[E]) {
+        let dst = self.data.as_mut();
+        let len_in_bytes = src.len().checked_mul(E::SIZE).expect("length overflow");
+        let total_len = self.offset.checked_add(len_in_bytes).expect("offset overflow");
+        assert!(total_len <= dst.len());
+        // `Order::Native` is the compile-time byte order; a plain copy is only
+        // correct when that order is also the target's native one.
+        let native = cfg!(any(
+            feature = "NE",
+            all(target_endian = "big", feature = "BE"),
+            all(target_endian = "little", not(any(feature = "BE", feature = "NE"))),
+        ));
+        if self.order == Order::Native && native {
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    src.as_ptr() as *const u8,
+                    dst.as_mut_ptr().add(self.offset),
+                    len_in_bytes,
+                );
+            }
+        } else {
+            for (i, &e) in src.iter().enumerate() {
+                unsafe {
+                    e.__write_at_with__(dst.as_mut_ptr().add(self.offset + i * E::SIZE), self.order)
+                };
+            }
+        }
+        self.offset = total_len;
+    }
+
+    /// Writes a value of type `E` to the data view, using the given runtime byte `order`
+    /// instead of the view's native byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{DataView, Order};
+    ///
+    /// let mut view = DataView::from([0; 2]);
+    ///
+    /// view.write_with::<u16>(42, Order::Little);
+    /// ```
+    /// # Panics
+    /// Panics if the offset is out of bounds.
+    #[inline]
+    pub fn write_with<E: Endian>(&mut self, num: E, order: Order) {
+        let dst = self.data.as_mut();
+        let total_len = self.offset.checked_add(E::SIZE).expect("offset overflow");
+        assert!(total_len <= dst.len());
+        unsafe { num.__write_at_with__(dst.as_mut_ptr().add(self.offset), order) };
+        self.offset = total_len;
+    }
+
     /// Writes a slice into the data view.
     ///
     /// # Examples
@@ -207,6 +585,99 @@ impl<T: AsMut<[u8]>> DataView<T> {
         }
         self.offset = total_len;
     }
+
+    /// Writes a value of type `E` to the data view, returning `Err(())` instead of
+    /// panicking if the offset is out of bounds. The offset is left unchanged on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([0; 1]);
+    ///
+    /// assert_eq!(view.try_write::<u8>(12), Ok(()));
+    /// assert_eq!(view.try_write::<u8>(34), Err(()));
+    /// ```
+    #[inline]
+    pub fn try_write<E: Endian>(&mut self, num: E) -> Result<(), ()> {
+        let dst = self.data.as_mut();
+        let total_len = self.offset.checked_add(E::SIZE).ok_or(())?;
+        if total_len > dst.len() {
+            return Err(());
+        }
+        unsafe { num_write_at(num, dst.as_mut_ptr().add(self.offset)) };
+        self.offset = total_len;
+        Ok(())
+    }
+
+    /// Writes a slice into the data view, returning `Err(())` instead of panicking if
+    /// the offset is out of bounds. The offset is left unchanged on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([0; 2]);
+    ///
+    /// assert_eq!(view.try_write_slice([1, 2]), Ok(()));
+    /// assert_eq!(view.try_write_slice([3, 4]), Err(()));
+    /// ```
+    #[inline]
+    pub fn try_write_slice(&mut self, slice: impl AsRef<[u8]>) -> Result<(), ()> {
+        let src = slice.as_ref();
+        let dst = self.data.as_mut();
+        let count = src.len();
+        let total_len = self.offset.checked_add(count).ok_or(())?;
+        if total_len > dst.len() {
+            return Err(());
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr().add(self.offset), count);
+        }
+        self.offset = total_len;
+        Ok(())
+    }
+
+    /// Reinterprets the next `size_of::<U>()` bytes as a mutable reference to `U`,
+    /// without copying, and advances the offset past them.
+    ///
+    /// Returns `None` (leaving the offset unchanged) if `offset + size_of::<U>()` overflows,
+    /// if there aren't enough bytes remaining, or if the address at the current offset doesn't meet
+    /// `U`'s alignment requirement (always satisfied when `U: Unaligned`).
+    #[inline]
+    pub fn cast_mut<U: FromBytes + AsBytes>(&mut self) -> Option<&mut U> {
+        let dst = self.data.as_mut();
+        let total_len = self.offset.checked_add(size_of::<U>())?;
+        let bytes = dst.get_mut(self.offset..total_len)?;
+        let ptr = bytes.as_mut_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        self.offset = total_len;
+        Some(unsafe { &mut *(ptr as *mut U) })
+    }
+
+    /// Reinterprets the next `size_of::<U>() * len` bytes as a mutable slice of `U`,
+    /// without copying, and advances the offset past them.
+    ///
+    /// Returns `None` (leaving the offset unchanged) if `size_of::<U>() * len` overflows,
+    /// if there aren't enough bytes remaining, or if the address at the current offset doesn't meet
+    /// `U`'s alignment requirement (always satisfied when `U: Unaligned`).
+    #[inline]
+    pub fn cast_slice_mut<U: FromBytes + AsBytes>(&mut self, len: usize) -> Option<&mut [U]> {
+        let len_in_bytes = len.checked_mul(size_of::<U>())?;
+        let dst = self.data.as_mut();
+        let total_len = self.offset.checked_add(len_in_bytes)?;
+        let bytes = dst.get_mut(self.offset..total_len)?;
+        let ptr = bytes.as_mut_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        self.offset = total_len;
+        Some(unsafe { slice::from_raw_parts_mut(ptr as *mut U, len) })
+    }
 }
 
 impl<T> From<T> for DataView<T> {
@@ -3,20 +3,63 @@
 use super::*;
 use core::fmt::Debug;
 
+/// Byte order, selectable at runtime.
+///
+/// Unlike the `BE`/`NE` cargo features, which fix the byte order for the whole
+/// binary at compile time, an `Order` value can be picked per read/write so a
+/// single process can handle both little-endian and big-endian data (e.g. a
+/// file format whose endianness is only known after inspecting a header byte).
+///
+/// See [`DataView::with_order`], [`DataView::read_with`], [`DataView::write_with`],
+/// [`View::read_at_with`] and [`View::write_at_with`].
+///
+/// [`DataView::with_order`]: crate::DataView::with_order
+/// [`DataView::read_with`]: crate::DataView::read_with
+/// [`DataView::write_with`]: crate::DataView::write_with
+/// [`View::read_at_with`]: crate::View::read_at_with
+/// [`View::write_at_with`]: crate::View::write_at_with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Little-endian byte order.
+    Little,
+    /// Big-endian byte order.
+    Big,
+    /// The byte order selected at compile time: little-endian by default,
+    /// big-endian with the `BE` feature, or the target's native byte order with
+    /// the `NE` feature. This is the default, and requires no byte swapping
+    /// when it matches the target's native byte order.
+    #[default]
+    Native,
+}
+
 /// This trait contains unsafe methods for efficiently reading and writing data.
 ///
 /// Those Methods are unsafe because they do not check the index bounds.
 ///
 /// And safely used by internal. And shouldn't expect to be used by user.
 pub trait Endian: Copy + Default + Debug + PartialEq + PartialOrd + Sized + Send + Sync + Unpin {
+    /// The size, in bytes, of this type's encoding.
+    const SIZE: usize;
+
     unsafe fn __write_at__(self, dst: *mut u8);
     unsafe fn __read_at__(src: *const u8) -> Self;
+
+    /// Same as [`__write_at__`], but the byte order is picked at runtime instead of compile time.
+    ///
+    /// [`__write_at__`]: Self::__write_at__
+    unsafe fn __write_at_with__(self, dst: *mut u8, order: Order);
+    /// Same as [`__read_at__`], but the byte order is picked at runtime instead of compile time.
+    ///
+    /// [`__read_at__`]: Self::__read_at__
+    unsafe fn __read_at_with__(src: *const u8, order: Order) -> Self;
 }
 
 macro_rules! impl_endian_for {
     [$($rty:ty)*] => ($(
         // impl Endian for $rty {}
         impl Endian for $rty {
+            const SIZE: usize = size_of::<$rty>();
+
             unsafe fn __write_at__(self, dst: *mut u8) {
                 #[cfg(all(target_endian = "big", not(any(feature = "BE", feature = "NE"))))]
                 return write_unaligned(self.to_le_bytes().as_ptr(), dst, size_of::<$rty>());
@@ -41,6 +84,27 @@ macro_rules! impl_endian_for {
                 ))]
                 return read_unaligned(src);
             }
+
+            unsafe fn __write_at_with__(self, dst: *mut u8, order: Order) {
+                match order {
+                    // `Order::Native` keeps the zero-overhead compile-time fast path.
+                    Order::Native => self.__write_at__(dst),
+                    Order::Little => {
+                        write_unaligned(self.to_le_bytes().as_ptr(), dst, size_of::<$rty>())
+                    }
+                    Order::Big => {
+                        write_unaligned(self.to_be_bytes().as_ptr(), dst, size_of::<$rty>())
+                    }
+                }
+            }
+            unsafe fn __read_at_with__(src: *const u8, order: Order) -> Self {
+                match order {
+                    // `Order::Native` keeps the zero-overhead compile-time fast path.
+                    Order::Native => Self::__read_at__(src),
+                    Order::Little => Self::from_le_bytes(read_unaligned(src)),
+                    Order::Big => Self::from_be_bytes(read_unaligned(src)),
+                }
+            }
         }
     )*);
 }
@@ -60,3 +124,21 @@ unsafe fn read_unaligned<T>(src: *const u8) -> T {
 unsafe fn write_unaligned(src: *const u8, dst: *mut u8, count: usize) {
     ptr::copy_nonoverlapping(src, dst, count);
 }
+
+/// Reads a value of type `E: Endian` from `ptr`, using the compile-time byte order.
+///
+/// # Safety
+/// Calling this method with an out-of-bounds index is *[undefined behavior]*
+#[inline]
+pub(crate) unsafe fn num_from<E: Endian>(ptr: *const u8) -> E {
+    E::__read_at__(ptr)
+}
+
+/// Writes `num` to `ptr`, using the compile-time byte order.
+///
+/// # Safety
+/// Calling this method with an out-of-bounds index is *[undefined behavior]*
+#[inline]
+pub(crate) unsafe fn num_write_at<E: Endian>(num: E, ptr: *mut u8) {
+    num.__write_at__(ptr)
+}
@@ -0,0 +1,63 @@
+//! `std::io::Read`/`Write` bridge, enabled by the `std` feature.
+//!
+//! This lets [`DataView`] drop into existing `io`-based codecs (serde readers,
+//! compression streams) without an intermediate copy, while the crate stays
+//! `#![no_std]` by default. Mirrors the `Reader`/`Writer` adapters in the `bytes` crate.
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use crate::DataView;
+
+impl<T: AsRef<[u8]>> Read for DataView<T> {
+    /// Copies as many bytes as fit into `buf` out of the remaining slice, advancing
+    /// `offset` by the number of bytes copied. Never fails.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let data = self.remaining_slice();
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        self.offset += len;
+        Ok(len)
+    }
+
+    /// Fills `buf` completely, or returns an [`UnexpectedEof`](ErrorKind::UnexpectedEof)
+    /// error (leaving the offset unchanged) if the remaining slice is too short.
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        let slice = self
+            .read_slice(buf.len())
+            .ok_or_else(|| Error::from(ErrorKind::UnexpectedEof))?;
+        buf.copy_from_slice(slice);
+        Ok(())
+    }
+}
+
+impl<T: AsMut<[u8]>> Write for DataView<T> {
+    /// Copies as many bytes as fit from `buf` into the underlying buffer, advancing
+    /// `offset` by the number of bytes copied. Never fails.
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let dst = self.data.as_mut();
+        let offset = self.offset.min(dst.len());
+        let remaining = &mut dst[offset..];
+        let len = remaining.len().min(buf.len());
+        remaining[..len].copy_from_slice(&buf[..len]);
+        self.offset = offset + len;
+        Ok(len)
+    }
+
+    /// `DataView` writes are never buffered, so this is a no-op.
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Writes all of `buf`, or returns a [`WriteZero`](ErrorKind::WriteZero) error
+    /// (leaving the offset unchanged) if the underlying buffer is too short.
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        let dst = self.data.as_mut();
+        let total_len = match self.offset.checked_add(buf.len()) {
+            Some(total_len) if total_len <= dst.len() => total_len,
+            _ => return Err(Error::from(ErrorKind::WriteZero)),
+        };
+        dst[self.offset..total_len].copy_from_slice(buf);
+        self.offset = total_len;
+        Ok(())
+    }
+}
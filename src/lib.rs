@@ -2,14 +2,24 @@
 #![allow(clippy::result_unit_err)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod chain;
 mod dataview;
 mod endian;
+#[cfg(feature = "std")]
+mod io;
+mod marker;
+mod take;
 mod view;
 
 use core::mem::size_of;
 use core::ptr;
-use endian::*;
 
+pub use chain::{chain, Chain};
 pub use dataview::DataView;
-pub use endian::Endian;
+pub use endian::{Endian, Order};
+pub use marker::{AsBytes, FromBytes, Unaligned};
+pub use take::Take;
 pub use view::View;
\ No newline at end of file
@@ -0,0 +1,54 @@
+//! Marker traits for zero-copy casting between byte slices and typed references.
+//!
+//! These mirror the traits of the same name in the `zerocopy` crate, trimmed down
+//! to what [`View::cast_at`] and [`DataView::cast`] need.
+//!
+//! [`View::cast_at`]: crate::View::cast_at
+//! [`DataView::cast`]: crate::DataView::cast
+
+/// Types that may be safely constructed from any byte pattern of the correct size.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that the type has no padding bytes and no
+/// validity invariants narrower than "any bit pattern of `size_of::<Self>()` bytes"
+/// (so no `bool`, `char`, `NonZero*`, references, or enums with unfilled niches),
+/// and that every field is itself `FromBytes`.
+pub unsafe trait FromBytes {}
+
+/// Types that may be safely reinterpreted as a byte slice.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that every bit pattern the type can hold is a
+/// valid byte sequence, i.e. the type has no padding bytes.
+pub unsafe trait AsBytes {}
+
+/// Types whose alignment requirement is `1`.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that `align_of::<Self>() == 1`, so that a
+/// reference to the type may validly be constructed from a byte slice at any
+/// offset, without an alignment check.
+pub unsafe trait Unaligned {}
+
+macro_rules! impl_from_bytes_and_as_bytes_for {
+    [$($rty:ty)*] => ($(
+        unsafe impl FromBytes for $rty {}
+        unsafe impl AsBytes for $rty {}
+    )*);
+}
+
+impl_from_bytes_and_as_bytes_for!(
+    u8 u16 u32 u64 u128
+    i8 i16 i32 i64 i128
+    usize isize
+    f32 f64
+);
+
+macro_rules! impl_unaligned_for {
+    [$($rty:ty)*] => ($(unsafe impl Unaligned for $rty {})*);
+}
+
+impl_unaligned_for!(u8 i8);
@@ -0,0 +1,104 @@
+use crate::{DataView, Endian};
+
+/// An adapter that limits how many bytes can be read from the wrapped [`DataView`],
+/// exactly like `bytes::Buf::take`. Once the limit is reached, `read`, `read_slice`
+/// and `read_buf` return `None` even if the underlying buffer has more data.
+///
+/// This is valuable for safely parsing length-prefixed sub-messages: decode a length
+/// field, [`take`](DataView::take) it to scope a nested parser, and the parser is
+/// guaranteed not to read past its declared frame even on malformed input.
+///
+/// # Examples
+///
+/// ```
+/// use data_view::DataView;
+///
+/// let mut view = DataView::from([1, 2, 3, 4]).take(2);
+///
+/// assert_eq!(view.read::<u8>(), Some(1));
+/// assert_eq!(view.read::<u8>(), Some(2));
+/// assert_eq!(view.read::<u8>(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Take<T> {
+    pub view: DataView<T>,
+    limit: usize,
+}
+
+impl<T> Take<T> {
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{DataView, Take};
+    ///
+    /// let view = Take::new(DataView::from([0; 16]), 4);
+    /// ```
+    pub const fn new(view: DataView<T>, limit: usize) -> Self {
+        Self { view, limit }
+    }
+
+    /// Returns the number of bytes that can still be read before the limit is reached.
+    #[inline]
+    pub const fn remaining_limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Sets the maximum number of bytes that can still be read.
+    #[inline]
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+    }
+
+    /// Consumes the `Take`, returning the wrapped [`DataView`].
+    #[inline]
+    pub fn into_inner(self) -> DataView<T> {
+        self.view
+    }
+}
+
+impl<T: AsRef<[u8]>> Take<T> {
+    /// Reads a value of type `E: Endian`, returning `None` once the limit is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::DataView;
+    ///
+    /// let mut view = DataView::from([0; 4]).take(2);
+    ///
+    /// assert_eq!(view.read::<u16>(), Some(0));
+    /// assert_eq!(view.read::<u16>(), None);
+    /// ```
+    #[inline]
+    pub fn read<E: Endian>(&mut self) -> Option<E> {
+        if E::SIZE > self.limit {
+            return None;
+        }
+        let value = self.view.read::<E>()?;
+        self.limit -= E::SIZE;
+        Some(value)
+    }
+
+    /// Reads a slice from the current offset, returning `None` once the limit is reached.
+    #[inline]
+    pub fn read_slice(&mut self, len: usize) -> Option<&[u8]> {
+        if len > self.limit {
+            return None;
+        }
+        let slice = self.view.read_slice(len)?;
+        self.limit -= len;
+        Some(slice)
+    }
+
+    /// Reads a fixed-size buffer from the current offset, returning `None` once the
+    /// limit is reached.
+    #[inline]
+    pub fn read_buf<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if N > self.limit {
+            return None;
+        }
+        let buf = self.view.read_buf::<N>()?;
+        self.limit -= N;
+        Some(buf)
+    }
+}
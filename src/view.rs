@@ -1,4 +1,7 @@
 use crate::endian::*;
+use crate::marker::{AsBytes, FromBytes};
+use core::mem::{align_of, size_of};
+use core::slice;
 
 /// A data view for reading and writing data in byte array.
 ///
@@ -27,6 +30,21 @@ pub trait View {
     /// ```
     fn read_at<E: Endian>(&self, offset: usize) -> Option<E>;
 
+    /// Reads a value of type `E: Endian` from view, using the given runtime byte `order`
+    /// instead of the compile-time byte order used by [`read_at`](Self::read_at).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{View, Order};
+    ///
+    /// let mut buf: [u8; 2] = [12, 34];
+    ///
+    /// assert_eq!(buf.read_at_with::<u8>(0, Order::Big).unwrap(), 12);
+    /// assert_eq!(buf.read_at_with::<u8>(1, Order::Big).unwrap(), 34);
+    /// ```
+    fn read_at_with<E: Endian>(&self, offset: usize, order: Order) -> Option<E>;
+
     // /// Reads a value of type `E: Endian` from view, without doing bounds checking.
     // /// For a safe alternative see [`read_at`].
     // ///
@@ -65,6 +83,92 @@ pub trait View {
     /// # Panics
     /// Panics if the offset is out of bounds.
     fn write_at<E: Endian>(&mut self, offset: usize, num: E);
+
+    /// Writes a value of type `E: Endian` to data view, returning `Err(())` instead of
+    /// panicking if the offset is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::View;
+    ///
+    /// let mut buf: [u8; 1] = [0; 1];
+    ///
+    /// assert_eq!(buf.try_write_at(0, 12_u8), Ok(()));
+    /// assert_eq!(buf.try_write_at(1, 34_u8), Err(()));
+    /// ```
+    fn try_write_at<E: Endian>(&mut self, offset: usize, num: E) -> Result<(), ()>;
+
+    /// Writes a value of type `E: Endian` to data view, using the given runtime byte `order`
+    /// instead of the compile-time byte order used by [`write_at`](Self::write_at).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::{View, Order};
+    ///
+    /// let mut buf: [u8; 2] = [0; 2];
+    ///
+    /// buf.write_at_with(0, 12_u8, Order::Big);
+    /// buf.write_at_with(1, 34_u8, Order::Big);
+    /// assert_eq!(buf, [12, 34]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the offset is out of bounds.
+    fn write_at_with<E: Endian>(&mut self, offset: usize, num: E, order: Order);
+
+    /// Reinterprets the bytes at `offset` as a reference to `U`, without copying.
+    ///
+    /// Returns `None` if `offset + size_of::<U>()` overflows or is out of bounds,
+    /// or if the address at `offset` doesn't meet `U`'s alignment requirement (always satisfied
+    /// when `U: Unaligned`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::View;
+    ///
+    /// let buf: [u8; 2] = [12, 34];
+    /// assert_eq!(*buf.cast_at::<u8>(1).unwrap(), 34);
+    /// ```
+    fn cast_at<U: FromBytes>(&self, offset: usize) -> Option<&U>;
+
+    /// Reinterprets the `size_of::<U>() * len` bytes at `offset` as a slice of `len`
+    /// values of `U`, without copying.
+    ///
+    /// Returns `None` if `offset + size_of::<U>() * len` overflows or is out of bounds,
+    /// or if the address at `offset` doesn't meet `U`'s alignment requirement (always satisfied when
+    /// `U: Unaligned`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_view::View;
+    ///
+    /// let buf: [u8; 4] = [1, 2, 3, 4];
+    /// assert_eq!(buf.cast_slice_at::<u8>(1, 2).unwrap(), [2, 3]);
+    /// ```
+    fn cast_slice_at<U: FromBytes>(&self, offset: usize, len: usize) -> Option<&[U]>;
+
+    /// Reinterprets the bytes at `offset` as a mutable reference to `U`, without copying.
+    ///
+    /// Returns `None` if `offset + size_of::<U>()` overflows or is out of bounds,
+    /// or if the address at `offset` doesn't meet `U`'s alignment requirement (always satisfied
+    /// when `U: Unaligned`).
+    fn cast_at_mut<U: FromBytes + AsBytes>(&mut self, offset: usize) -> Option<&mut U>;
+
+    /// Reinterprets the `size_of::<U>() * len` bytes at `offset` as a mutable slice of
+    /// `len` values of `U`, without copying.
+    ///
+    /// Returns `None` if `offset + size_of::<U>() * len` overflows or is out of bounds,
+    /// or if the address at `offset` doesn't meet `U`'s alignment requirement (always satisfied when
+    /// `U: Unaligned`).
+    fn cast_slice_at_mut<U: FromBytes + AsBytes>(
+        &mut self,
+        offset: usize,
+        len: usize,
+    ) -> Option<&mut [U]>;
 }
 
 impl View for [u8] {
@@ -83,7 +187,78 @@ impl View for [u8] {
 
     #[inline]
     fn write_at<E: Endian>(&mut self, offset: usize, num: E) {
-        assert!(offset + E::SIZE <= self.len());
+        let total_len = offset.checked_add(E::SIZE).expect("offset overflow");
+        assert!(total_len <= self.len());
         unsafe { num_write_at(num, self.as_mut_ptr().add(offset)) };
     }
+
+    #[inline]
+    fn try_write_at<E: Endian>(&mut self, offset: usize, num: E) -> Result<(), ()> {
+        match offset.checked_add(E::SIZE) {
+            Some(total_len) if total_len <= self.len() => {
+                unsafe { num_write_at(num, self.as_mut_ptr().add(offset)) };
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    #[inline]
+    fn read_at_with<E: Endian>(&self, offset: usize, order: Order) -> Option<E> {
+        let bytes = self.get(offset..offset + E::SIZE)?;
+        Some(unsafe { E::__read_at_with__(bytes.as_ptr(), order) })
+    }
+
+    #[inline]
+    fn write_at_with<E: Endian>(&mut self, offset: usize, num: E, order: Order) {
+        let total_len = offset.checked_add(E::SIZE).expect("offset overflow");
+        assert!(total_len <= self.len());
+        unsafe { num.__write_at_with__(self.as_mut_ptr().add(offset), order) };
+    }
+
+    #[inline]
+    fn cast_at<U: FromBytes>(&self, offset: usize) -> Option<&U> {
+        let bytes = self.get(offset..offset.checked_add(size_of::<U>())?)?;
+        let ptr = bytes.as_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        Some(unsafe { &*(ptr as *const U) })
+    }
+
+    #[inline]
+    fn cast_slice_at<U: FromBytes>(&self, offset: usize, len: usize) -> Option<&[U]> {
+        let total_len = len.checked_mul(size_of::<U>())?;
+        let bytes = self.get(offset..offset.checked_add(total_len)?)?;
+        let ptr = bytes.as_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts(ptr as *const U, len) })
+    }
+
+    #[inline]
+    fn cast_at_mut<U: FromBytes + AsBytes>(&mut self, offset: usize) -> Option<&mut U> {
+        let bytes = self.get_mut(offset..offset.checked_add(size_of::<U>())?)?;
+        let ptr = bytes.as_mut_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        Some(unsafe { &mut *(ptr as *mut U) })
+    }
+
+    #[inline]
+    fn cast_slice_at_mut<U: FromBytes + AsBytes>(
+        &mut self,
+        offset: usize,
+        len: usize,
+    ) -> Option<&mut [U]> {
+        let total_len = len.checked_mul(size_of::<U>())?;
+        let bytes = self.get_mut(offset..offset.checked_add(total_len)?)?;
+        let ptr = bytes.as_mut_ptr();
+        if !(ptr as usize).is_multiple_of(align_of::<U>()) {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts_mut(ptr as *mut U, len) })
+    }
 }